@@ -4,6 +4,10 @@ extern crate rustling_ontology_en as en;
 
 use std::result;
 
+mod locale;
+
+pub use locale::{normalize_bcp47_tag, ClockConvention, DateOrder, Locale, LocaleHints};
+
 macro_rules! lang_enum {
     ([$($lang:ident),*]) => {
         /// Enumerates all language supported for the general purpose ontology.
@@ -22,8 +26,13 @@ macro_rules! lang_enum {
 
         impl std::str::FromStr for Lang {
             type Err = String;
+            /// Accepts either a bare language code ("EN") or a full BCP-47
+            /// tag ("en-US", "en-Latn-US"): only the primary language subtag
+            /// is matched, region and script subtags are ignored here (see
+            /// [`Locale`] to retain them).
             fn from_str(it: &str) -> result::Result<Lang, Self::Err> {
-                match &*it.to_uppercase() {
+                let primary_subtag = it.split('-').next().unwrap_or(it);
+                match &*primary_subtag.to_uppercase() {
                     $( stringify!($lang) => Ok(Lang::$lang),  )*
                     _ => Err(format!("Unknown language {}", it)),
                 }
@@ -0,0 +1,201 @@
+//! BCP-47 / IETF language tag support: [`Locale`] parses a full tag and
+//! exposes the region-dependent resolver hints it implies via
+//! [`Locale::hints`].
+
+use std::str::FromStr;
+
+use crate::Lang;
+
+/// Preferred ordering for ambiguous all-numeric dates like "03/04/2020".
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+}
+
+/// Preferred clock convention for resolving ambiguous times.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClockConvention {
+    Twelve,
+    TwentyFour,
+}
+
+/// Region-dependent resolver hints implied by a [`Locale`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LocaleHints {
+    pub date_order: DateOrder,
+    pub clock_convention: ClockConvention,
+    /// ISO-8601 day number the week starts on (1 = Monday, 7 = Sunday).
+    pub week_start: u8,
+}
+
+impl LocaleHints {
+    fn for_lang_default(lang: Lang) -> LocaleHints {
+        match lang {
+            Lang::EN => LocaleHints {
+                date_order: DateOrder::MonthDayYear,
+                clock_convention: ClockConvention::Twelve,
+                week_start: 7,
+            },
+        }
+    }
+
+    fn for_region(lang: Lang, region: &str) -> LocaleHints {
+        match (lang, region) {
+            (Lang::EN, "US") => LocaleHints {
+                date_order: DateOrder::MonthDayYear,
+                clock_convention: ClockConvention::Twelve,
+                week_start: 7,
+            },
+            (Lang::EN, "GB") | (Lang::EN, "AU") | (Lang::EN, "NZ") | (Lang::EN, "IE") => {
+                LocaleHints {
+                    date_order: DateOrder::DayMonthYear,
+                    clock_convention: ClockConvention::TwentyFour,
+                    week_start: 1,
+                }
+            }
+            (Lang::EN, "CA") => LocaleHints {
+                date_order: DateOrder::MonthDayYear,
+                clock_convention: ClockConvention::Twelve,
+                week_start: 7,
+            },
+            // Unknown region for an otherwise-supported language: fall back
+            // to the language default rather than error.
+            _ => LocaleHints::for_lang_default(lang),
+        }
+    }
+}
+
+/// A BCP-47 tag resolved to a supported [`Lang`], with the region and script
+/// subtags retained for locale-sensitive resolution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    pub lang: Lang,
+    pub region: Option<String>,
+    pub script: Option<String>,
+}
+
+impl Locale {
+    /// The date-ordering, clock-convention and week-start hints this locale
+    /// implies, falling back to the language default when the region is
+    /// unknown or absent.
+    pub fn hints(&self) -> LocaleHints {
+        match &self.region {
+            Some(region) => LocaleHints::for_region(self.lang, region),
+            None => LocaleHints::for_lang_default(self.lang),
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// Parses a full BCP-47 tag ("en", "en-US", "en-Latn-US"), normalizing
+    /// case per RFC 5646 before matching the primary subtag to a [`Lang`].
+    fn from_str(tag: &str) -> Result<Locale, String> {
+        let normalized = normalize_bcp47_tag(tag);
+        let mut subtags = normalized.split('-');
+        let lang = subtags
+            .next()
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| format!("Empty language tag {}", tag))
+            .and_then(Lang::from_str)?;
+
+        // Only the well-formed `lang[-script][-region]` shape is supported:
+        // script is 4 ASCII letters, region is either 2 ASCII letters or 3
+        // ASCII digits (RFC 5646 §2.2.4/§2.2.5). The first subtag matching a
+        // given shape wins, so a later extension subtag of the same shape
+        // (e.g. the "ca" in "en-US-u-ca-gregory") can't overwrite it.
+        let mut region = None;
+        let mut script = None;
+        for subtag in subtags {
+            let is_script = subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic());
+            let is_region = (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+                || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()));
+
+            if script.is_none() && is_script {
+                script = Some(subtag.to_string());
+            } else if region.is_none() && is_region {
+                region = Some(subtag.to_string());
+            }
+        }
+
+        Ok(Locale {
+            lang,
+            region,
+            script,
+        })
+    }
+}
+
+/// Normalizes a BCP-47 tag per RFC 5646 §2.1.1: the language subtag is
+/// lowercased, the script subtag is title-cased, and the region subtag is
+/// uppercased.
+pub fn normalize_bcp47_tag(tag: &str) -> String {
+    tag.split('-')
+        .enumerate()
+        .map(|(i, subtag)| {
+            if i == 0 {
+                subtag.to_lowercase()
+            } else if subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic()) {
+                let mut chars = subtag.chars();
+                match chars.next() {
+                    Some(first) => {
+                        first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase()
+                    }
+                    None => String::new(),
+                }
+            } else {
+                subtag.to_uppercase()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_case_per_rfc_5646() {
+        assert_eq!(normalize_bcp47_tag("EN"), "en");
+        assert_eq!(normalize_bcp47_tag("en-us"), "en-US");
+        assert_eq!(normalize_bcp47_tag("EN-LATN-us"), "en-Latn-US");
+    }
+
+    #[test]
+    fn parses_locale_with_region() {
+        let locale = Locale::from_str("en-GB").unwrap();
+        assert_eq!(locale.lang, Lang::EN);
+        assert_eq!(locale.region.as_deref(), Some("GB"));
+        assert_eq!(locale.hints().date_order, DateOrder::DayMonthYear);
+        assert_eq!(locale.hints().week_start, 1);
+    }
+
+    #[test]
+    fn parses_locale_with_script_and_region() {
+        let locale = Locale::from_str("en-Latn-US").unwrap();
+        assert_eq!(locale.lang, Lang::EN);
+        assert_eq!(locale.script.as_deref(), Some("Latn"));
+        assert_eq!(locale.region.as_deref(), Some("US"));
+    }
+
+    #[test]
+    fn unknown_region_falls_back_to_language_default() {
+        let locale = Locale::from_str("en-ZZ").unwrap();
+        assert_eq!(locale.hints(), LocaleHints::for_lang_default(Lang::EN));
+    }
+
+    #[test]
+    fn lang_from_str_accepts_full_tag() {
+        assert_eq!(Lang::from_str("en-US").unwrap(), Lang::EN);
+        assert_eq!(Lang::from_str("EN-GB").unwrap(), Lang::EN);
+    }
+
+    #[test]
+    fn trailing_variant_subtag_does_not_overwrite_region() {
+        let locale = Locale::from_str("en-US-1901").unwrap();
+        assert_eq!(locale.region.as_deref(), Some("US"));
+    }
+}
@@ -24,8 +24,9 @@ extern crate rustling_ontology_grammar as grammar;
 extern crate rustling_ontology_moment;
 extern crate rustling_ontology_values;
 extern crate serde;
+extern crate serde_json;
 
-pub use grammar::{dims, Lang};
+pub use grammar::{dims, ClockConvention, DateOrder, Lang, Locale, LocaleHints};
 pub use rustling::RustlingResult;
 pub use rustling::{AttemptInto, ParsedNode, ParserMatch, ParsingAnalysis, Range, Sym, Value};
 pub use rustling_ontology_moment::Grain;
@@ -36,7 +37,9 @@ pub use rustling_ontology_values::output::{Output, OutputKind};
 pub use rustling_ontology_values::{IdentityContext, ParsingContext, ResolverContext};
 
 mod mapper;
+mod numeric_date;
 mod parser;
+mod snapshot;
 mod tagger;
 
 pub use tagger::CandidateTagger;
@@ -91,6 +94,24 @@ impl Parser {
         self.parse_with_kind_order(input, context, &all_output)
     }
 
+    /// Same as `parse`, but resolves ambiguous all-numeric dates ("03/04/2020")
+    /// the way `locale` implies rather than `Lang::EN`'s month-day-first
+    /// default, by rewriting them before the ordinary parse runs (see
+    /// `numeric_date::rewrite_ambiguous_numeric_dates`). Clock convention and
+    /// week start are computed by `locale.hints()` too, but applying them
+    /// needs `ResolverContext` itself to carry locale-aware fields, which
+    /// this crate can't add from here.
+    pub fn parse_for_locale(
+        &self,
+        input: &str,
+        context: &ResolverContext,
+        locale: &Locale,
+    ) -> RustlingResult<Vec<ParserMatch<Output>>> {
+        let rewritten =
+            crate::numeric_date::rewrite_ambiguous_numeric_dates(input, locale.hints().date_order);
+        self.parse(&rewritten, context)
+    }
+
     pub fn analyse_with_kind_order(
         &self,
         examples: Vec<&str>,
@@ -121,6 +142,205 @@ impl Parser {
     pub fn num_text_patterns(&self) -> usize {
         self.0.num_text_patterns()
     }
+
+    /// Parses `input` in fixed-size, overlapping character windows instead
+    /// of all at once, so the tagger only ever runs over `window_chars`
+    /// characters at a time regardless of document size. `input` itself must
+    /// already be resident in memory as a `&str`; for a document too large
+    /// to hold at once, use `parse_stream_from_reader`, which also bounds
+    /// how much of the document is buffered. `overlap_chars` must cover the
+    /// longest matchable entity, or a match straddling a window boundary is
+    /// silently lost; matches within the overlap are de-duplicated, keeping
+    /// the higher-`probalog` one.
+    pub fn parse_stream(
+        &self,
+        input: &str,
+        context: &ResolverContext,
+        window_chars: usize,
+        overlap_chars: usize,
+    ) -> RustlingResult<Vec<ParserMatch<Output>>> {
+        assert!(
+            window_chars > overlap_chars,
+            "window_chars ({}) must be greater than overlap_chars ({})",
+            window_chars,
+            overlap_chars
+        );
+
+        if input.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let step = window_chars - overlap_chars;
+        let mut matches = Vec::new();
+        let mut window_start_byte = 0usize;
+        let mut window_start_char = 0usize;
+
+        loop {
+            let remainder = &input[window_start_byte..];
+            let window_end_byte = remainder
+                .char_indices()
+                .nth(window_chars)
+                .map(|(b, _)| window_start_byte + b)
+                .unwrap_or_else(|| input.len());
+
+            for m in self.parse(&input[window_start_byte..window_end_byte], context)? {
+                matches.push(shift_match(m, window_start_byte, window_start_char));
+            }
+
+            if window_end_byte == input.len() {
+                break;
+            }
+
+            let advance_byte = remainder
+                .char_indices()
+                .nth(step)
+                .map(|(b, _)| window_start_byte + b)
+                .unwrap_or_else(|| input.len());
+            window_start_char += step;
+            window_start_byte = advance_byte;
+        }
+
+        Ok(merge_windowed_matches(matches))
+    }
+
+    /// Same as `parse_stream`, but reads `reader` in 8 KiB chunks only until
+    /// `window_chars` characters are buffered, instead of requiring the whole
+    /// document in memory up front, so both the IO and the parsing stay
+    /// bounded by the window size rather than the document's length.
+    pub fn parse_stream_from_reader<R: ::std::io::Read>(
+        &self,
+        mut reader: R,
+        context: &ResolverContext,
+        window_chars: usize,
+        overlap_chars: usize,
+    ) -> RustlingResult<Vec<ParserMatch<Output>>> {
+        assert!(
+            window_chars > overlap_chars,
+            "window_chars ({}) must be greater than overlap_chars ({})",
+            window_chars,
+            overlap_chars
+        );
+
+        const READ_CHUNK_BYTES: usize = 8 * 1024;
+        let step = window_chars - overlap_chars;
+
+        let mut pending_bytes: Vec<u8> = Vec::new();
+        let mut buffer = String::new();
+        let mut read_buf = [0u8; READ_CHUNK_BYTES];
+        let mut eof = false;
+        let mut matches = Vec::new();
+        let mut document_byte_offset = 0usize;
+        let mut document_char_offset = 0usize;
+
+        loop {
+            while !eof && buffer.chars().count() < window_chars {
+                let n = reader
+                    .read(&mut read_buf)
+                    .map_err(|e| format!("failed to read input: {}", e))?;
+                if n == 0 {
+                    eof = true;
+                    break;
+                }
+                pending_bytes.extend_from_slice(&read_buf[..n]);
+                match ::std::str::from_utf8(&pending_bytes) {
+                    Ok(valid) => {
+                        buffer.push_str(valid);
+                        pending_bytes.clear();
+                    }
+                    Err(e) => {
+                        let valid_len = e.valid_up_to();
+                        buffer.push_str(
+                            ::std::str::from_utf8(&pending_bytes[..valid_len])
+                                .expect("validated up to valid_len"),
+                        );
+                        pending_bytes.drain(..valid_len);
+                    }
+                }
+            }
+
+            if buffer.is_empty() {
+                break;
+            }
+
+            let window_char_len = buffer.chars().count().min(window_chars);
+            let window_byte_len = buffer
+                .char_indices()
+                .nth(window_char_len)
+                .map(|(b, _)| b)
+                .unwrap_or_else(|| buffer.len());
+
+            for m in self.parse(&buffer[..window_byte_len], context)? {
+                matches.push(shift_match(m, document_byte_offset, document_char_offset));
+            }
+
+            let at_end = eof && window_byte_len == buffer.len();
+            if at_end {
+                break;
+            }
+
+            let advance_chars = step.min(window_char_len);
+            let advance_byte_len = buffer
+                .char_indices()
+                .nth(advance_chars)
+                .map(|(b, _)| b)
+                .unwrap_or_else(|| buffer.len());
+
+            document_char_offset += advance_chars;
+            document_byte_offset += advance_byte_len;
+            buffer.drain(..advance_byte_len);
+        }
+
+        Ok(merge_windowed_matches(matches))
+    }
+}
+
+/// Translates a window-relative match back to absolute document offsets.
+fn shift_match(
+    m: ParserMatch<Output>,
+    byte_offset: usize,
+    char_offset: usize,
+) -> ParserMatch<Output> {
+    ParserMatch {
+        byte_range: Range(m.byte_range.0 + byte_offset, m.byte_range.1 + byte_offset),
+        char_range: Range(m.char_range.0 + char_offset, m.char_range.1 + char_offset),
+        parsing_tree_height: m.parsing_tree_height,
+        parsing_tree_num_nodes: m.parsing_tree_num_nodes,
+        value: m.value,
+        probalog: m.probalog,
+        latent: m.latent,
+    }
+}
+
+/// Merges matches collected across overlapping windows into a single
+/// ordered, non-overlapping list, keeping the higher-`probalog` match
+/// whenever two char ranges overlap. This assumes `overlap_chars` was large
+/// enough that an overlap only ever means "the same entity seen twice from
+/// different windows" — it has no way to tell that case apart from two
+/// distinct entities that happen to overlap, and an undersized
+/// `overlap_chars` silently drops the lower-probability one instead of
+/// erroring.
+fn merge_windowed_matches(mut matches: Vec<ParserMatch<Output>>) -> Vec<ParserMatch<Output>> {
+    matches.sort_by(|a, b| {
+        a.char_range.0.cmp(&b.char_range.0).then(
+            b.probalog
+                .partial_cmp(&a.probalog)
+                .unwrap_or(::std::cmp::Ordering::Equal),
+        )
+    });
+
+    let mut merged: Vec<ParserMatch<Output>> = Vec::with_capacity(matches.len());
+    for m in matches {
+        match merged.last() {
+            Some(last) if m.char_range.0 < last.char_range.1 => {
+                if m.probalog > last.probalog {
+                    merged.pop();
+                    merged.push(m);
+                }
+            }
+            _ => merged.push(m),
+        }
+    }
+    merged
 }
 
 /// Obtain a parser for a given language.
@@ -143,6 +363,44 @@ pub fn build_raw_parser(lang: Lang) -> RustlingResult<RawParser> {
     ))
 }
 
+/// Builds a parser for `lang` from a model read from `model_reader`, instead
+/// of the one baked into the binary at compile time via `build_raw_parser`.
+/// `model_reader` must yield an rmp-encoded model matching `lang`'s rules
+/// and feature set, such as one produced by `train_parser_to_writer` — this
+/// lets downstream users retrain and ship a `.rmp` file separately rather
+/// than rebuilding the crate to pick up a retrained or domain-tuned model.
+pub fn load_parser<R: ::std::io::Read>(lang: Lang, model_reader: R) -> RustlingResult<Parser> {
+    let rules = grammar::rules(lang)?;
+    let model = ::rmp_serde::decode::from_read(model_reader)?;
+    Ok(Parser(crate::RawParser::new(
+        rules,
+        model,
+        crate::parser::FeatureExtractor(),
+    )))
+}
+
+/// Trains a parser for `lang` exactly like `train_parser`, additionally
+/// serializing the trained model (rmp-encoded, same format `build_raw_parser`
+/// expects) to `writer` so it can be reloaded later via `load_parser` without
+/// retraining.
+pub fn train_parser_to_writer<W: ::std::io::Write>(
+    lang: Lang,
+    writer: W,
+) -> RustlingResult<Parser> {
+    let rules = grammar::rules(lang)?;
+    let examples = grammar::examples(lang);
+    let model = ::rustling::train::train(&rules, examples, crate::parser::FeatureExtractor())?;
+    let mut buffered_writer = ::std::io::BufWriter::new(writer);
+    ::rmp_serde::encode::write(&mut buffered_writer, &model)?;
+    ::std::io::Write::flush(&mut buffered_writer)
+        .map_err(|e| format!("failed to flush serialized model: {}", e))?;
+    Ok(Parser(::rustling::Parser::new(
+        rules,
+        model,
+        crate::parser::FeatureExtractor(),
+    )))
+}
+
 pub fn train_parser(lang: Lang) -> RustlingResult<Parser> {
     let rules = grammar::rules(lang)?;
     let examples = grammar::examples(lang);
@@ -170,6 +428,31 @@ mod tests {
         assert_eq!(1521082, int.0);
     }
 
+    #[test]
+    fn examples_match_snapshots() {
+        // No golden file ships in this tree yet (see `regenerate_snapshots`
+        // below); skip rather than fail until a maintainer has generated one,
+        // so a fresh checkout doesn't fail this test out of the box.
+        for lang in Lang::all() {
+            if !snapshot::has_snapshot(lang) {
+                continue;
+            }
+            snapshot::check_snapshot(lang).unwrap();
+        }
+    }
+
+    // Maintainer command: `cargo test regenerate_snapshots -- --ignored`.
+    // Re-resolves every example and overwrites the golden snapshot files;
+    // review the diff before committing it alongside a deliberate grammar
+    // change.
+    #[test]
+    #[ignore]
+    fn regenerate_snapshots() {
+        for lang in Lang::all() {
+            snapshot::regenerate_snapshot(lang).unwrap();
+        }
+    }
+
     #[test]
     #[ignore]
     fn time_resolve_complex_train_sentence() {
@@ -0,0 +1,137 @@
+//! Rewrites ambiguous `D/M/YYYY`-shaped numeric dates in input text so a
+//! grammar tuned for `Lang::EN`'s month-day-first default (e.g. "03/04/2020"
+//! is always March 4th) resolves them the way a region preferring day-month
+//! order actually means.
+//!
+//! This operates on the input text rather than inside the resolver: full
+//! support needs `ResolverContext` itself to carry the ordering (see
+//! `Locale::hints`), which depends on `rustling_ontology_values` gaining
+//! locale-aware fields this crate doesn't have visibility into. Rewriting
+//! the ambiguous case before parsing is the part of the request this crate
+//! can deliver on its own.
+
+use crate::DateOrder;
+
+/// Swaps the day/month components of ambiguous numeric dates (both
+/// components `<= 12`, so either reading is valid) when `date_order` is
+/// day-month-first. Leaves `text` untouched for month-day-first locales and
+/// for unambiguous dates (e.g. "25/04/2020", where 25 can only be a day).
+pub fn rewrite_ambiguous_numeric_dates(text: &str, date_order: DateOrder) -> String {
+    if date_order == DateOrder::MonthDayYear {
+        return text.to_string();
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let preceded_by_digit = i > 0 && chars[i - 1].is_ascii_digit();
+        if !preceded_by_digit {
+            if let Some((first, second, year, consumed)) = match_numeric_date(&chars[i..]) {
+                let followed_by_digit = chars
+                    .get(i + consumed)
+                    .map(|c| c.is_ascii_digit())
+                    .unwrap_or(false);
+                if !followed_by_digit && first <= 12 && second <= 12 {
+                    // Swap the original digit runs rather than re-padding them,
+                    // so the rewritten text keeps `text`'s length and offsets.
+                    let span: String = chars[i..i + consumed].iter().collect();
+                    let parts: Vec<&str> = span.split('/').collect();
+                    out.push_str(&format!("{}/{}/{}", parts[1], parts[0], year));
+                    i += consumed;
+                    continue;
+                }
+            }
+        }
+        out.push(chars[i]);
+        i += 1;
+    }
+    out
+}
+
+/// Matches a `D{1,2}/D{1,2}/D{4}` numeric date at the start of `chars`,
+/// returning its day/month components, the year text, and chars consumed.
+fn match_numeric_date(chars: &[char]) -> Option<(u32, u32, String, usize)> {
+    let (first, first_len) = take_digits(chars, 2)?;
+    let mut pos = first_len;
+    if chars.get(pos) != Some(&'/') {
+        return None;
+    }
+    pos += 1;
+
+    let (second, second_len) = take_digits(&chars[pos..], 2)?;
+    pos += second_len;
+    if chars.get(pos) != Some(&'/') {
+        return None;
+    }
+    pos += 1;
+
+    let (year, year_len) = take_digits(&chars[pos..], 4)?;
+    if year_len != 4 {
+        return None;
+    }
+    pos += year_len;
+
+    Some((first, second, format!("{:04}", year), pos))
+}
+
+fn take_digits(chars: &[char], max: usize) -> Option<(u32, usize)> {
+    let count = chars.iter().take(max).take_while(|c| c.is_ascii_digit()).count();
+    if count == 0 {
+        return None;
+    }
+    chars[..count]
+        .iter()
+        .collect::<String>()
+        .parse()
+        .ok()
+        .map(|value| (value, count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrites_ambiguous_date_to_month_first_order() {
+        assert_eq!(
+            rewrite_ambiguous_numeric_dates("03/04/2020", DateOrder::DayMonthYear),
+            "04/03/2020"
+        );
+    }
+
+    #[test]
+    fn leaves_unambiguous_date_unchanged() {
+        assert_eq!(
+            rewrite_ambiguous_numeric_dates("25/04/2020", DateOrder::DayMonthYear),
+            "25/04/2020"
+        );
+    }
+
+    #[test]
+    fn leaves_text_unchanged_for_month_first_locales() {
+        assert_eq!(
+            rewrite_ambiguous_numeric_dates("03/04/2020", DateOrder::MonthDayYear),
+            "03/04/2020"
+        );
+    }
+
+    #[test]
+    fn rewrites_only_the_matched_span_in_surrounding_text() {
+        assert_eq!(
+            rewrite_ambiguous_numeric_dates(
+                "meet on 03/04/2020 at noon",
+                DateOrder::DayMonthYear
+            ),
+            "meet on 04/03/2020 at noon"
+        );
+    }
+
+    #[test]
+    fn does_not_touch_longer_digit_runs() {
+        assert_eq!(
+            rewrite_ambiguous_numeric_dates("03/04/20201", DateOrder::DayMonthYear),
+            "03/04/20201"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Snapshot regression harness built from `grammar::examples`.
+//!
+//! Every example in `grammar::examples(lang)` already carries the text used
+//! to train the parser and the dimension it should resolve to. This module
+//! walks that corpus, resolves each example with `Parser::parse_with_kind_order`,
+//! and compares the result against a golden snapshot file committed alongside
+//! the crate, so a grammar change that silently breaks resolution (e.g.
+//! "twenty-one" stops parsing as 21) fails with a diff instead of relying on
+//! one-off `#[test]` functions per regression. Resolution is currently
+//! scoped to `OutputKind::Number` (see `SNAPSHOT_KINDS`) so the snapshot
+//! doesn't depend on the "now" `ResolverContext::default()` resolves
+//! against.
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{build_parser, Lang, OutputKind, ResolverContext, RustlingResult};
+
+/// The resolved outputs for a single example, keyed by its source text so
+/// snapshot diffs read as "this sentence used to resolve to X, now Y".
+///
+/// Outputs are stored as their `Debug` rendering rather than serialized
+/// directly: `Output` (and the `Moment`/`Interval` types it wraps) come from
+/// `rustling_ontology_values`/`rustling_ontology_moment` and aren't known to
+/// derive `Serialize`/`Deserialize`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct SnapshotEntry {
+    text: String,
+    outputs: Vec<String>,
+}
+
+fn snapshot_path(lang: Lang) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(format!("{}.snapshot.json", lang.to_string().to_lowercase()))
+}
+
+/// Whether a golden snapshot has been generated for `lang` yet.
+pub fn has_snapshot(lang: Lang) -> bool {
+    snapshot_path(lang).is_file()
+}
+
+// Scoped to `OutputKind::Number` rather than `OutputKind::all()`: resolving
+// against `ResolverContext::default()` anchors to "now", so a snapshot of
+// date/time examples would mismatch daily as the reference instant moves.
+// Number resolution has no such dependency. Widen this once either
+// `ResolverContext` gains a fixed-reference constructor, or the other
+// `OutputKind` variants this crate doesn't have visibility into are known
+// to be similarly time-independent.
+const SNAPSHOT_KINDS: &[OutputKind] = &[OutputKind::Number];
+
+fn resolve_examples(lang: Lang) -> RustlingResult<Vec<SnapshotEntry>> {
+    let parser = build_parser(lang)?;
+    let context = ResolverContext::default();
+
+    let mut entries = grammar::examples(lang)
+        .into_iter()
+        .map(|example| {
+            let text = example.text().to_string();
+            let outputs = parser
+                .parse_with_kind_order(&text, &context, SNAPSHOT_KINDS)?
+                .into_iter()
+                .map(|m| format!("{:?}", m.value))
+                .collect();
+            Ok(SnapshotEntry { text, outputs })
+        })
+        .collect::<RustlingResult<Vec<_>>>()?;
+
+    entries.sort_by(|a, b| a.text.cmp(&b.text));
+    Ok(entries)
+}
+
+/// Maintainer command: re-resolves every example for `lang` and overwrites
+/// its golden snapshot file. Run this after a deliberate grammar change,
+/// review the diff it produces, and commit the updated snapshot alongside
+/// the change.
+pub fn regenerate_snapshot(lang: Lang) -> RustlingResult<()> {
+    let entries = resolve_examples(lang)?;
+    let json = ::serde_json::to_string_pretty(&entries)
+        .map_err(|e| format!("failed to serialize snapshot for {}: {}", lang.to_string(), e))?;
+    let path = snapshot_path(lang);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+    }
+    fs::write(&path, json).map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+    Ok(())
+}
+
+/// Test entry point: re-resolves every example for `lang` and fails with a
+/// diff against the committed golden snapshot if resolution drifted.
+pub fn check_snapshot(lang: Lang) -> RustlingResult<()> {
+    let actual = resolve_examples(lang)?;
+    let path = snapshot_path(lang);
+    let raw = fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "no snapshot at {} ({}); run the regenerate_snapshots maintainer test first",
+            path.display(),
+            e
+        )
+    })?;
+    let expected: Vec<SnapshotEntry> = ::serde_json::from_str(&raw)
+        .map_err(|e| format!("failed to parse snapshot {}: {}", path.display(), e))?;
+
+    if actual != expected {
+        let mut diff = String::new();
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            if a != e {
+                diff.push_str(&format!(
+                    "  \"{}\": expected {:?}, got {:?}\n",
+                    a.text, e.outputs, a.outputs
+                ));
+            }
+        }
+        if actual.len() != expected.len() {
+            diff.push_str(&format!(
+                "  example count changed: expected {}, got {}\n",
+                expected.len(),
+                actual.len()
+            ));
+        }
+        return Err(format!(
+            "snapshot mismatch for {}:\n{}",
+            lang.to_string(),
+            diff
+        )
+        .into());
+    }
+    Ok(())
+}